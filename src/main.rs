@@ -1,30 +1,59 @@
+use std::collections::HashSet;
+use std::env;
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Read};
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write;
+use std::mem;
 use std::os::unix::process::CommandExt;
-use std::process::Command;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread::spawn;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::Result;
 use eframe::{App, AppCreator, CreationContext, egui, Storage};
 use eframe::egui::{Color32, Event, Key, Label, Widget};
+use eframe::egui::text::{LayoutJob, TextFormat};
 use itertools::Itertools;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::egui::{Context, Frame, RichText, Spinner, Visuals};
-use crate::shuttle::{Github, Item, Jenkins, Matcher, Provider};
+use crate::shuttle::{Action, Auth, DockerRegistry, Github, Item, Jenkins, Matcher, Provider, RestProvider};
 
 mod shuttle;
 
 enum ShuttleState {
     Loading,
     Loaded(LoadedState),
+    Launching,
+}
+
+impl ShuttleState {
+    /// Returns the `Loaded` state, turning `Loading` into an empty one first if needed.
+    fn loaded_or_init(&mut self) -> &mut LoadedState {
+        if !matches!(self, ShuttleState::Loaded(_)) {
+            *self = ShuttleState::Loaded(LoadedState {
+                all: Vec::new(),
+                filtered: None,
+                selected: 0,
+            });
+        }
+
+        match self {
+            ShuttleState::Loaded(state) => state,
+            _ => unreachable!(),
+        }
+    }
 }
 
 struct LoadedState {
     all: Vec<Item>,
-    filtered: Option<Vec<Item>>,
+    /// The currently filtered items, each paired with the haystack char indices the
+    /// query matched (empty if the matcher doesn't report positions).
+    filtered: Option<Vec<(Item, Vec<usize>)>>,
     selected: usize,
 }
 
@@ -33,7 +62,7 @@ impl LoadedState {
         self.filtered = None;
 
         if query.is_empty() {
-            self.filtered = Some(self.all.clone())
+            self.filtered = Some(self.all.iter().cloned().map(|item| (item, Vec::new())).collect());
         } else {
             self.update_filtered(matcher, query);
         }
@@ -44,18 +73,24 @@ impl LoadedState {
 
         let selected_value = self.filtered
             .as_ref()
-            .and_then(|values| values.get(self.selected));
+            .and_then(|values| values.get(self.selected))
+            .map(|(item, _)| item.value.clone());
 
-        let values_to_filter = self.filtered.as_ref().unwrap_or(&self.all);
+        // narrow down the previous filter result if there is one, otherwise start
+        // from the full item list
+        let values_to_filter = match &self.filtered {
+            Some(filtered) => filtered.iter().map(|(item, _)| item.clone()).collect_vec(),
+            None => self.all.clone(),
+        };
 
         let filtered_new = matcher
-            .matches(query.as_str(), values_to_filter)
+            .matches_with_positions(query.as_str(), &values_to_filter)
             .into_iter()
-            .cloned()
+            .map(|(item, positions)| (item.clone(), positions))
             .collect_vec();
 
         self.selected = selected_value
-            .and_then(|val| self.filtered.iter().flatten().position(|item| item.value == val.value))
+            .and_then(|val| filtered_new.iter().position(|(item, _)| item.value == val))
             .unwrap_or_default();
 
         self.filtered = Some(filtered_new);
@@ -63,58 +98,103 @@ impl LoadedState {
 }
 
 struct ShuttleApp {
-    query: String,
+    query: Arc<Mutex<String>>,
     state: Arc<Mutex<ShuttleState>>,
-    matcher: Box<dyn Matcher>,
+    matcher: Arc<dyn Matcher>,
     providers: Vec<Arc<dyn Provider>>,
 }
 
 impl ShuttleApp {
-    pub fn new(providers: Vec<Arc<dyn Provider>>, matcher: Box<dyn Matcher>) -> Self {
+    pub fn new(providers: Vec<Arc<dyn Provider>>, matcher: Arc<dyn Matcher>) -> Self {
         Self {
-            query: String::new(),
+            query: Arc::new(Mutex::new(String::new())),
             state: Arc::new(ShuttleState::Loading.into()),
             providers,
             matcher,
         }
     }
 
-    pub fn launch(&self, url: &str) {
-        Command::new("xdg-open")
-            .arg(url)
-            .exec();
+    pub fn launch(&self, action: &Action, ctx: &Context) {
+        match action {
+            Action::Open(url) => {
+                Command::new("xdg-open")
+                    .arg(url)
+                    .exec();
+            }
+
+            Action::Copy(text) => {
+                match Command::new("xclip").args(["-selection", "clipboard"]).stdin(Stdio::piped()).spawn() {
+                    Ok(mut child) => {
+                        if let Some(mut stdin) = child.stdin.take() {
+                            if let Err(err) = stdin.write_all(text.as_bytes()) {
+                                eprintln!("failed to copy to clipboard: {}", err);
+                            }
+                        }
+
+                        drop(child.wait());
+                    }
+
+                    Err(err) => eprintln!("failed to copy to clipboard: {}", err),
+                }
+            }
+
+            Action::Clone { clone_url } => {
+                let mut state = self.state.lock().unwrap();
+                let previous = mem::replace(&mut *state, ShuttleState::Launching);
+                drop(state);
+                ctx.request_repaint();
+
+                let clone_url = clone_url.clone();
+                let ctx = ctx.clone();
+                let state = Arc::clone(&self.state);
+
+                spawn(move || {
+                    if let Err(err) = clone_and_enter(&clone_url) {
+                        eprintln!("failed to clone {}: {}", clone_url, err);
+
+                        // cloning failed, so there's no shell to hand off to: go back to
+                        // the state we were in before, rather than leaving the UI stuck
+                        // showing the "launching" spinner forever
+                        *state.lock().unwrap() = previous;
+                        ctx.request_repaint();
+                    }
+                });
+            }
+        }
     }
 
     fn handle_events(&mut self, ctx: &&Context, frame: &mut eframe::Frame) {
-        let state = &mut *self.state.lock().unwrap();
-
         let mut require_update = false;
         let mut require_reset = false;
 
         let mut move_steps: i32 = 0;
         let mut launch = false;
+        let mut launch_alt = false;
 
 
         for event in &ctx.input().events {
             match event {
                 Event::Text(t) => {
-                    self.query += t;
+                    *self.query.lock().unwrap() += t;
                     require_update = true;
                 }
 
                 Event::Key { key: Key::Backspace, pressed: true, .. } => {
-                    if let Some((pos, _)) = self.query.char_indices().last() {
-                        self.query.remove(pos);
+                    let mut query = self.query.lock().unwrap();
+                    if let Some((pos, _)) = query.char_indices().last() {
+                        query.remove(pos);
                         require_reset = true;
                     }
                 }
 
                 Event::Key { key: Key::W, pressed: true, modifiers } if modifiers.ctrl => {
-                    if let Some(pos) = self.query.trim_end().rfind(' ') {
-                        self.query.truncate(pos + 1);
+                    let mut query = self.query.lock().unwrap();
+                    if let Some(pos) = query.trim_end().rfind(' ') {
+                        let pos = pos + 1;
+                        query.truncate(pos);
                         require_reset = true;
                     } else {
-                        self.query.truncate(0);
+                        query.truncate(0);
                         require_reset = true;
                     }
                 }
@@ -123,8 +203,9 @@ impl ShuttleApp {
                     frame.quit();
                 }
 
-                Event::Key { key: Key::Enter, pressed: true, .. } => {
+                Event::Key { key: Key::Enter, pressed: true, modifiers } => {
                     launch = true;
+                    launch_alt = modifiers.ctrl;
                 }
 
                 Event::Key { key: Key::ArrowUp, pressed: true, .. } => {
@@ -139,35 +220,56 @@ impl ShuttleApp {
             }
         }
 
-        match state {
-            ShuttleState::Loading => {}
+        let mut action_to_launch = None;
+        let query = self.query.lock().unwrap().clone();
 
-            ShuttleState::Loaded(state) => {
-                if state.filtered.is_none() {
-                    require_reset = true;
-                }
+        {
+            let state = &mut *self.state.lock().unwrap();
 
-                if require_reset {
-                    state.update_filtered_reset(self.matcher.as_ref(), &self.query);
-                } else if require_update {
-                    state.update_filtered(self.matcher.as_ref(), &self.query);
-                }
+            match state {
+                ShuttleState::Loading => {}
+
+                ShuttleState::Launching => {}
+
+                ShuttleState::Loaded(state) => {
+                    if state.filtered.is_none() {
+                        require_reset = true;
+                    }
 
-                if let Some(filtered) = state.filtered.as_ref() {
-                    if !filtered.is_empty() {
-                        state.selected = (state.selected as i32 + move_steps).rem_euclid(filtered.len() as _) as _;
+                    if require_reset {
+                        state.update_filtered_reset(self.matcher.as_ref(), &query);
+                    } else if require_update {
+                        state.update_filtered(self.matcher.as_ref(), &query);
                     }
 
-                    if launch {
-                        if let Some(selected) = filtered.get(state.selected) {
-                            //println!("launching {:?}", selected.value);
-                            self.launch(&selected.value);
-                            return frame.quit();
+                    if let Some(filtered) = state.filtered.as_ref() {
+                        if !filtered.is_empty() {
+                            state.selected = (state.selected as i32 + move_steps).rem_euclid(filtered.len() as _) as _;
+                        }
+
+                        if launch {
+                            if let Some((selected, _)) = filtered.get(state.selected) {
+                                action_to_launch = Some(if launch_alt {
+                                    selected.alt_action.clone().unwrap_or_else(|| selected.action.clone())
+                                } else {
+                                    selected.action.clone()
+                                });
+                            }
                         }
                     }
                 }
             }
         }
+
+        if let Some(action) = action_to_launch {
+            let quits = matches!(action, Action::Open(_) | Action::Copy(_));
+
+            self.launch(&action, *ctx);
+
+            if quits {
+                return frame.quit();
+            }
+        }
     }
 
     fn paint(&mut self, ctx: &Context) {
@@ -183,7 +285,7 @@ impl ShuttleApp {
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
                     ui.set_height(32.0);
-                    let query_str = String::from("> ") + &self.query;
+                    let query_str = String::from("> ") + &self.query.lock().unwrap();
                     Label::new(RichText::new(query_str).color(Color32::GOLD)).ui(ui);
                 });
 
@@ -201,14 +303,15 @@ impl ShuttleApp {
                         .enumerate()
                         .skip(state.selected.saturating_sub(rows/2).min(items_count.saturating_sub(rows)));
 
-                    for (idx, item) in items_iter {
+                    for (idx, (item, positions)) in items_iter {
                         let selected = state.selected == idx;
                         let color: Color32 = if selected { Color32::WHITE } else { Color32::GRAY };
 
                         ui.horizontal(|ui| {
                             ui.set_height(24.0);
 
-                            let label = Label::new(RichText::new(&item.label).color(color)).ui(ui);
+                            let job = highlighted_label(&item.label, positions, color);
+                            let label = Label::new(job).ui(ui);
 
                             label.rect
                         });
@@ -219,7 +322,7 @@ impl ShuttleApp {
                     }
                 }
 
-                if let ShuttleState::Loading = state {
+                if matches!(state, ShuttleState::Loading | ShuttleState::Launching) {
                     ui.centered_and_justified(|ui| {
                         ui.add(Spinner::new().size(32.0));
                     });
@@ -236,6 +339,42 @@ impl App for ShuttleApp {
     }
 }
 
+const HIGHLIGHT_COLOR: Color32 = Color32::from_rgb(255, 214, 10);
+
+/// Builds a [`LayoutJob`] for `label` with the characters at `positions` (char
+/// indices, as returned by [`Matcher::matches_with_positions`]) rendered in a
+/// brighter highlight color and the rest in `base_color`.
+fn highlighted_label(label: &str, positions: &[usize], base_color: Color32) -> LayoutJob {
+    let positions: HashSet<usize> = positions.iter().copied().collect();
+
+    let mut job = LayoutJob::default();
+    let mut run = String::new();
+    let mut run_highlighted = false;
+
+    for (idx, ch) in label.chars().enumerate() {
+        let is_highlighted = positions.contains(&idx);
+
+        if !run.is_empty() && is_highlighted != run_highlighted {
+            append_run(&mut job, &run, run_highlighted, base_color);
+            run.clear();
+        }
+
+        run_highlighted = is_highlighted;
+        run.push(ch);
+    }
+
+    if !run.is_empty() {
+        append_run(&mut job, &run, run_highlighted, base_color);
+    }
+
+    job
+}
+
+fn append_run(job: &mut LayoutJob, text: &str, highlighted: bool, base_color: Color32) {
+    let color = if highlighted { HIGHLIGHT_COLOR } else { base_color };
+    job.append(text, 0.0, TextFormat { color, ..Default::default() });
+}
+
 fn create_app(cc: &CreationContext<'_>, app: ShuttleApp) -> Box<dyn App> {
     cc.egui_ctx.set_visuals(Visuals::dark());
 
@@ -243,69 +382,181 @@ fn create_app(cc: &CreationContext<'_>, app: ShuttleApp) -> Box<dyn App> {
 
     let ctx = cc.egui_ctx.clone();
     let state_arc = Arc::clone(&app.state);
-
+    let query_arc = Arc::clone(&app.query);
+    let matcher = Arc::clone(&app.matcher);
     let providers = app.providers.clone();
 
     spawn(move || {
-        let items = load_items(&providers).unwrap();
+        let cache = read_cache();
 
-        let mut state = state_arc.lock().unwrap();
+        // serve whatever we have cached right away, so the UI is interactive at once
+        if let Some(cache) = &cache {
+            state_arc.lock().unwrap().loaded_or_init().all = cache.items.clone();
+            ctx.request_repaint();
+        }
 
-        *state = ShuttleState::Loaded(
-            LoadedState {
-                all: items,
-                filtered: None,
-                selected: 0,
+        if let Some(cache) = &cache {
+            if !cache.is_stale(cache_ttl()) {
+                return;
             }
-        );
+        }
+
+        // either there is no cache yet, or it is stale: re-query all providers.
+        // results are accumulated here and only swapped into the UI state once
+        // complete, so a stale-but-displayed item list isn't mixed with fresh
+        // results that may duplicate it.
+        let refreshed: Arc<Mutex<Vec<Item>>> = Arc::new(Mutex::new(Vec::new()));
+
+        load_items_from_providers(&providers, |batch| {
+            refreshed.lock().unwrap().extend(batch.clone());
+
+            // on a cold start (no cache at all) there is nothing to conflict with,
+            // so stream each provider's results into the UI as soon as they arrive
+            if cache.is_none() {
+                let mut state = state_arc.lock().unwrap();
+                let loaded = state.loaded_or_init();
+                loaded.all.extend(batch);
+                loaded.all.sort_by(|lhs, rhs| lhs.label.cmp(&rhs.label));
 
-        drop(state);
+                let query = query_arc.lock().unwrap().clone();
+                loaded.update_filtered_reset(matcher.as_ref(), &query);
 
-        ctx.request_repaint();
+                drop(state);
+                ctx.request_repaint();
+            }
+        });
+
+        let mut all = Arc::try_unwrap(refreshed).unwrap().into_inner().unwrap();
+        all.sort_by(|lhs, rhs| lhs.label.cmp(&rhs.label));
+
+        if let Err(err) = write_cache(&all) {
+            eprintln!("failed to write item cache: {}", err);
+        }
+
+        if cache.is_some() {
+            let mut state = state_arc.lock().unwrap();
+            let loaded = state.loaded_or_init();
+            loaded.all = all;
+
+            let query = query_arc.lock().unwrap().clone();
+            loaded.update_filtered_reset(matcher.as_ref(), &query);
+
+            drop(state);
+            ctx.request_repaint();
+        }
     });
 
     Box::new(app)
 }
 
-fn load_items_from_providers(providers: &[Arc<dyn Provider>]) -> Result<Vec<Item>> {
-    use rayon::prelude::*;
+/// Clones `clone_url` into a directory under `~/src` (skipping the clone if it already
+/// exists) and then replaces the current process with the user's `$SHELL`, rooted at
+/// the checkout.
+fn clone_and_enter(clone_url: &str) -> Result<()> {
+    let target_dir = clone_target_dir(clone_url);
 
-    let items: Vec<_> = providers.par_iter()
-        .map(|prov| prov.load())
-        .collect();
+    if !target_dir.exists() {
+        let status = Command::new("git")
+            .arg("clone")
+            .arg(clone_url)
+            .arg(&target_dir)
+            .status()?;
 
-    let items = items.into_iter()
-        .flatten_ok()
-        .try_collect()
-        .map_err(|err| anyhow::anyhow!(err.to_string()))?;
+        anyhow::ensure!(status.success(), "git clone exited with {}", status);
+    }
 
-    Ok(items)
+    let shell = env::var("SHELL").unwrap_or_else(|_| "/bin/sh".into());
+
+    Err(Command::new(shell)
+        .current_dir(&target_dir)
+        .exec()
+        .into())
 }
 
-fn load_items_from_cache(r: impl Read) -> Result<Vec<Item>> {
-    let cache: ItemCache = serde_json::from_reader(BufReader::new(r))?;
-    Ok(cache.items)
+/// Derives a checkout directory from a `clone_url`, e.g.
+/// `https://github.com/foo/bar.git` -> `~/src/bar`.
+fn clone_target_dir(clone_url: &str) -> PathBuf {
+    let name = clone_url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .rsplit('/')
+        .next()
+        .unwrap_or(clone_url);
+
+    let home = env::var("HOME").unwrap_or_else(|_| "/tmp".into());
+    PathBuf::from(home).join("src").join(name)
 }
 
-fn load_items(providers: &[Arc<dyn Provider>]) -> Result<Vec<Item>> {
-    match File::open("/tmp/shuttle.cache") {
-        Ok(fp) => load_items_from_cache(fp),
-        Err(_) => {
-            let mut items = load_items_from_providers(providers)?;
+/// Queries every provider in parallel, calling `on_batch` with each provider's items
+/// as soon as it returns rather than waiting for the slowest one. A provider that
+/// fails is logged and simply contributes no items.
+fn load_items_from_providers(providers: &[Arc<dyn Provider>], on_batch: impl Fn(Vec<Item>) + Sync) {
+    use rayon::prelude::*;
 
-            // by default we sort all items by display label
-            items.sort_by(|lhs, rhs| lhs.label.cmp(&rhs.label));
+    providers.par_iter().for_each(|prov| {
+        match prov.load() {
+            Ok(items) => on_batch(items),
+            Err(err) => eprintln!("provider failed to load items: {}", err),
+        }
+    });
+}
 
-            // serialize all items into the item cache
-            let cache = ItemCache { items: items.clone() };
-            let writer = BufWriter::new(File::create("/tmp/shuttle.cache")?);
-            serde_json::to_writer(writer, &cache)?;
+const CACHE_PATH: &str = "/tmp/shuttle.cache";
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Reads the cache TTL from `SHUTTLE_CACHE_TTL_SECS`, falling back to
+/// `DEFAULT_CACHE_TTL` if unset or not a valid number of seconds.
+fn cache_ttl() -> Duration {
+    env::var("SHUTTLE_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_CACHE_TTL)
+}
 
-            Ok(items)
+fn read_cache() -> Option<ItemCache> {
+    let fp = File::open(CACHE_PATH).ok()?;
+
+    match serde_json::from_reader(BufReader::new(fp)) {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            eprintln!("failed to read item cache: {}", err);
+            None
         }
     }
 }
 
+fn write_cache(items: &[Item]) -> Result<()> {
+    let cache = ItemCache {
+        items: items.to_vec(),
+        cached_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+    };
+
+    // write to a temporary file first and rename it into place, so a reader never
+    // observes a partially written cache file
+    let tmp_path = format!("{}.tmp", CACHE_PATH);
+    serde_json::to_writer(BufWriter::new(File::create(&tmp_path)?), &cache)?;
+    std::fs::rename(&tmp_path, CACHE_PATH)?;
+
+    Ok(())
+}
+
+/// Reads a GitHub personal access token from `GITHUB_TOKEN`, if set.
+fn github_auth() -> Auth {
+    match env::var("GITHUB_TOKEN") {
+        Ok(token) => Auth::Bearer(token),
+        Err(_) => Auth::None,
+    }
+}
+
+/// Reads Jenkins basic-auth credentials from `JENKINS_USER`/`JENKINS_API_TOKEN`, if set.
+fn jenkins_auth() -> Auth {
+    match (env::var("JENKINS_USER"), env::var("JENKINS_API_TOKEN")) {
+        (Ok(username), Ok(password)) => Auth::Basic { username, password },
+        _ => Auth::None,
+    }
+}
+
 fn main() -> Result<()> {
     env_logger::init();
 
@@ -320,20 +571,23 @@ fn main() -> Result<()> {
     let gh = "https://srv-git-01-hh1.alinghi.tipp24.net/api/v3";
 
     let providers: Vec<Arc<dyn Provider>> = vec![
-        Arc::new(Github::new_with_endpoint("b2b", gh)),
-        Arc::new(Github::new_with_endpoint("eSailors", gh)),
-        Arc::new(Github::new_with_endpoint("iwg", gh)),
-        Arc::new(Github::new_with_endpoint("tipp24", gh)),
-        Arc::new(Github::new_with_endpoint("website", gh)),
-        Arc::new(Github::new_with_endpoint("zig", gh)),
-        Arc::new(Jenkins::new("http://jenkins.iwg.ham.sg-cloud.co.uk")),
-        Arc::new(Jenkins::new("http://platform-live.code.ham.sg-cloud.co.uk")),
-        Arc::new(Jenkins::new("https://platform-jenkins.test.h.zeal.zone")),
-        Arc::new(Jenkins::new("http://zig-jenkins.iwg.ham.sg-cloud.co.uk")),
+        Arc::new(Github::new_with_endpoint("b2b", gh).with_auth(github_auth())),
+        Arc::new(Github::new_with_endpoint("eSailors", gh).with_auth(github_auth())),
+        Arc::new(Github::new_with_endpoint("iwg", gh).with_auth(github_auth())),
+        Arc::new(Github::new_with_endpoint("tipp24", gh).with_auth(github_auth())),
+        Arc::new(Github::new_with_endpoint("website", gh).with_auth(github_auth())),
+        Arc::new(Github::new_with_endpoint("zig", gh).with_auth(github_auth())),
+        Arc::new(Jenkins::new("http://jenkins.iwg.ham.sg-cloud.co.uk").with_auth(jenkins_auth())),
+        Arc::new(Jenkins::new("http://platform-live.code.ham.sg-cloud.co.uk").with_auth(jenkins_auth())),
+        Arc::new(Jenkins::new("https://platform-jenkins.test.h.zeal.zone").with_auth(jenkins_auth())),
+        Arc::new(Jenkins::new("http://zig-jenkins.iwg.ham.sg-cloud.co.uk").with_auth(jenkins_auth())),
+        Arc::new(DockerRegistry::new("http://localhost:5000")),
+        Arc::new(RestProvider::new("https://wiki.tipp24.net/rest/api/space", "name", "_links.webui")
+            .with_items_path("results")),
     ];
 
-    // let matcher = Box::new(fuzzy_matcher::skim::SkimMatcherV2::default().ignore_case());
-    let matcher = Box::new(shuttle::SimpleMatcher);
+    // let matcher: Arc<dyn Matcher> = Arc::new(shuttle::SimpleMatcher);
+    let matcher: Arc<dyn Matcher> = Arc::new(shuttle::SubsequenceMatcher);
     let app = ShuttleApp::new(providers, matcher);
     let app_name = "shuttle";
     let app_creator: AppCreator = Box::new(|ctx| create_app(ctx, app));
@@ -344,4 +598,12 @@ fn main() -> Result<()> {
 #[derive(Serialize, Deserialize)]
 struct ItemCache {
     items: Vec<Item>,
+    cached_at: u64,
+}
+
+impl ItemCache {
+    fn is_stale(&self, ttl: Duration) -> bool {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        now.saturating_sub(self.cached_at) > ttl.as_secs()
+    }
 }
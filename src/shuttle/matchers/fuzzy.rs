@@ -4,7 +4,7 @@ use crate::{Item, Matcher};
 pub struct FuzzyMatcher<T>(T);
 
 impl<T> Matcher for FuzzyMatcher<T>
-    where T: fuzzy_matcher::FuzzyMatcher,
+    where T: fuzzy_matcher::FuzzyMatcher + Send + Sync,
 {
     fn matches<'a>(&self, query: &str, items: &'a [Item]) -> Vec<&'a Item> {
         items.iter()
@@ -0,0 +1,7 @@
+pub use fuzzy::FuzzyMatcher;
+pub use simple::SimpleMatcher;
+pub use subsequence::SubsequenceMatcher;
+
+mod fuzzy;
+mod simple;
+mod subsequence;
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+
+use crate::{Item, Matcher};
+
+const CONSECUTIVE_BONUS: i32 = 15;
+const WORD_BOUNDARY_BONUS: i32 = 10;
+
+/// A self-contained fuzzy matcher: the query matches an item if every character of
+/// the query occurs, in order, somewhere in the item's haystack. Unlike
+/// [`super::SimpleMatcher`], it doesn't require the query to be a contiguous
+/// substring, and unlike [`super::FuzzyMatcher`] it reports which haystack
+/// characters matched so the UI can highlight them.
+pub struct SubsequenceMatcher;
+
+impl Matcher for SubsequenceMatcher {
+    fn matches_with_positions<'a>(&self, query: &str, items: &'a [Item]) -> Vec<(&'a Item, Vec<usize>)> {
+        items.iter()
+            .filter_map(|item| {
+                score_subsequence(query, &item.haystack)
+                    .map(|(score, positions)| (score, item, positions))
+            })
+            .sorted_by_key(|(score, _item, _positions)| -score)
+            .map(|(_score, item, positions)| (item, positions))
+            .collect()
+    }
+}
+
+/// Greedily matches each character of `query` against the next occurrence of that
+/// character in `haystack`, scoring as it goes. Returns `None` if some query
+/// character isn't found at all. Both strings are compared case-insensitively, and
+/// the returned positions are char indices into the lowercased haystack.
+fn score_subsequence(query: &str, haystack: &str) -> Option<(i32, Vec<usize>)> {
+    let haystack: Vec<char> = haystack.to_lowercase().chars().collect();
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut search_from = 0;
+    let mut prev_match: Option<usize> = None;
+    let mut score = 0;
+
+    for &needle in &query {
+        let found_at = search_from + haystack[search_from..].iter().position(|&c| c == needle)?;
+
+        score += 1;
+
+        match prev_match {
+            // reward adjacent matches, penalize the gap between non-adjacent ones
+            Some(prev) if found_at == prev + 1 => score += CONSECUTIVE_BONUS,
+            Some(prev) => score -= (found_at - prev - 1) as i32,
+
+            // penalize characters skipped before the first match
+            None => score -= found_at as i32,
+        }
+
+        let at_word_boundary = found_at == 0
+            || matches!(haystack[found_at - 1], '/' | '-' | '_' | ' ');
+
+        if at_word_boundary {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        positions.push(found_at);
+        prev_match = Some(found_at);
+        search_from = found_at + 1;
+    }
+
+    Some((score, positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::score_subsequence;
+
+    #[test]
+    fn returns_none_when_a_character_is_missing() {
+        assert_eq!(score_subsequence("xyz", "shuttle"), None);
+    }
+
+    #[test]
+    fn matches_case_insensitively() {
+        assert!(score_subsequence("SHU", "shuttle").is_some());
+        assert!(score_subsequence("shu", "SHUTTLE").is_some());
+    }
+
+    #[test]
+    fn reports_char_positions_of_the_match() {
+        let (_score, positions) = score_subsequence("ttl", "shuttle").unwrap();
+        assert_eq!(positions, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher_than_scattered_ones() {
+        let (consecutive, _) = score_subsequence("abc", "abcxyz").unwrap();
+        let (scattered, _) = score_subsequence("abc", "axbxcxyz").unwrap();
+
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn word_boundary_matches_score_higher_than_mid_word_matches() {
+        let (at_boundary, _) = score_subsequence("to", "foo-tool").unwrap();
+        let (mid_word, _) = score_subsequence("to", "footool").unwrap();
+
+        assert!(at_boundary > mid_word);
+    }
+
+    #[test]
+    fn tied_subsequences_score_equally() {
+        let (first, _) = score_subsequence("ab", "ab").unwrap();
+        let (second, _) = score_subsequence("ab", "abx").unwrap();
+
+        assert_eq!(first, second);
+    }
+}
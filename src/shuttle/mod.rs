@@ -13,16 +13,53 @@ pub struct Item {
     /// The label will be used to display the item in the UI.
     pub label: String,
 
-    /// The URL that will be opened on selection.
+    /// A stable identity for the item, used as a cache key to re-locate the
+    /// selection across refilters. What happens on selection is entirely up to
+    /// `action`/`alt_action` — `value` is not necessarily a URL.
     pub value: String,
 
     /// The haystack field will be used for actual querying.
     pub haystack: String,
+
+    /// What happens when the item is selected with Enter.
+    pub action: Action,
+
+    /// What happens when the item is selected with Ctrl+Enter, if anything.
+    pub alt_action: Option<Action>,
+}
+
+/// Describes what selecting an item in the UI should do.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Action {
+    /// Open the given URL in the user's default application (e.g. via `xdg-open`).
+    Open(String),
+
+    /// Clone the given git repository and drop the user into a shell inside the checkout.
+    Clone { clone_url: String },
+
+    /// Put the given text on the clipboard, for payloads that aren't a URL to open
+    /// (e.g. a `docker pull` command).
+    Copy(String),
 }
 
-pub trait Matcher {
+pub trait Matcher: Send + Sync {
     /// Applies the query against the list of items and returns a list of matching items.
     /// The resulting list should be ordered by match score
     /// with the best match in the first place.
-    fn matches<'a>(&self, query: &str, items: &'a [Item]) -> Vec<&'a Item>;
+    fn matches<'a>(&self, query: &str, items: &'a [Item]) -> Vec<&'a Item> {
+        self.matches_with_positions(query, items)
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Like [`Matcher::matches`], but also returns the char indices into each matched
+    /// item's `haystack` that the query matched, so the UI can highlight them. Matchers
+    /// that can't report positions can leave this at its default, empty-positions impl.
+    fn matches_with_positions<'a>(&self, query: &str, items: &'a [Item]) -> Vec<(&'a Item, Vec<usize>)> {
+        self.matches(query, items)
+            .into_iter()
+            .map(|item| (item, Vec::new()))
+            .collect()
+    }
 }
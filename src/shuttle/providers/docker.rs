@@ -0,0 +1,80 @@
+use std::error::Error;
+
+use serde::Deserialize;
+
+use crate::shuttle::{Action, Item, Provider};
+use crate::shuttle::providers::{Auth, get_with_auth};
+
+/// A provider for a Docker (OCI) registry's HTTP API: lists every repository via
+/// `/v2/_catalog` and every tag of each repository via `/v2/<name>/tags/list`,
+/// producing one `Item` per `image:tag`.
+pub struct DockerRegistry {
+    endpoint: String,
+    auth: Auth,
+}
+
+impl DockerRegistry {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self { endpoint: endpoint.into(), auth: Auth::None }
+    }
+
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+}
+
+impl Provider for DockerRegistry {
+    fn load(&self) -> Result<Vec<Item>, Box<dyn Error + Send + Sync>> {
+        let endpoint = self.endpoint.trim_end_matches('/');
+
+        let catalog: Catalog = get_with_auth(&format!("{}/v2/_catalog", endpoint), &self.auth)?.into_json()?;
+
+        let mut items = Vec::new();
+
+        for name in catalog.repositories {
+            let tags_url = format!("{}/v2/{}/tags/list", endpoint, name);
+
+            let tags: TagsList = match get_with_auth(&tags_url, &self.auth) {
+                Ok(response) => response.into_json()?,
+                Err(err) => {
+                    eprintln!("failed to list tags for {}: {}", name, err);
+                    continue;
+                }
+            };
+
+            items.extend(tags.tags.into_iter().map(|tag| {
+                let reference = format!("{}:{}", name, tag);
+                let value = format!("docker pull {}/{}", registry_host(endpoint), reference);
+
+                Item {
+                    label: reference.clone(),
+                    haystack: reference,
+                    action: Action::Copy(value.clone()),
+                    alt_action: None,
+                    value,
+                }
+            }));
+        }
+
+        Ok(items)
+    }
+}
+
+/// Strips the scheme off an endpoint URL, so it reads like a registry host in a
+/// `docker pull` command (e.g. `https://registry.example.com` -> `registry.example.com`).
+fn registry_host(endpoint: &str) -> &str {
+    endpoint
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+}
+
+#[derive(Deserialize)]
+struct Catalog {
+    repositories: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct TagsList {
+    tags: Vec<String>,
+}
@@ -2,47 +2,86 @@ use std::error::Error;
 
 use serde::Deserialize;
 
-use crate::shuttle::{Item, Provider};
+use crate::shuttle::{Action, Item, Provider};
+use crate::shuttle::providers::{Auth, get_with_auth};
 
 pub struct Github {
     endpoint: String,
     orga: String,
+    auth: Auth,
 }
 
 impl Github {
     pub fn new(orga: impl Into<String>) -> Self {
-        Self {
-            endpoint: String::from("https://api.github.com"),
-            orga: orga.into(),
-        }
+        Self::new_with_endpoint(orga, "https://api.github.com")
     }
 
     pub fn new_with_endpoint(orga: impl Into<String>, endpoint: impl Into<String>) -> Self {
         Self {
             endpoint: endpoint.into(),
             orga: orga.into(),
+            auth: Auth::None,
         }
     }
+
+    /// Authenticate requests to this provider, e.g. with a personal access token.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
 }
 
 impl Provider for Github {
     fn load(&self) -> Result<Vec<Item>, Box<dyn Error + Send + Sync>> {
-        // TODO fetch more than the first page of URLs
-        let url = format!(
+        let mut repositories = Vec::new();
+
+        let mut url = Some(format!(
             "{}/orgs/{}/repos?sort=updated&per_page=100",
             self.endpoint.trim_end_matches('/'),
             self.orga,
-        );
+        ));
+
+        while let Some(next_url) = url {
+            let response = get_with_auth(&next_url, &self.auth)?;
+            let next = response.header("Link").and_then(parse_next_link);
+
+            let page: Vec<Repository> = response.into_json()?;
+            if page.is_empty() {
+                break;
+            }
+
+            repositories.extend(page);
+            url = next;
+        }
 
-        let repositories: Vec<Repository> = ureq::get(&url).call()?.into_json()?;
         Ok(repositories.into_iter().map(Into::into).collect())
     }
 }
 
+/// Parses the `rel="next"` url out of a GitHub `Link` response header, e.g.
+/// `<https://.../repos?page=2&per_page=100>; rel="next", <...>; rel="last"`.
+fn parse_next_link(header: &str) -> Option<String> {
+    header
+        .split(',')
+        .find_map(|part| {
+            let mut segments = part.split(';');
+            let url = segments.next()?.trim();
+            let is_next = segments
+                .any(|param| param.trim() == r#"rel="next""#);
+
+            if is_next {
+                Some(url.trim_start_matches('<').trim_end_matches('>').to_string())
+            } else {
+                None
+            }
+        })
+}
+
 #[derive(Deserialize)]
 struct Repository {
     full_name: String,
     html_url: String,
+    clone_url: String,
 }
 
 impl From<Repository> for Item {
@@ -50,7 +89,41 @@ impl From<Repository> for Item {
         Item {
             label: repo.full_name.clone(),
             haystack: repo.full_name,
-            value: repo.html_url,
+            value: repo.html_url.clone(),
+            action: Action::Open(repo.html_url),
+            alt_action: Some(Action::Clone { clone_url: repo.clone_url }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_next_link;
+
+    #[test]
+    fn finds_next_among_several_rels() {
+        let header = r#"<https://api.github.com/orgs/foo/repos?page=2>; rel="next", <https://api.github.com/orgs/foo/repos?page=5>; rel="last""#;
+
+        assert_eq!(
+            parse_next_link(header),
+            Some("https://api.github.com/orgs/foo/repos?page=2".to_string()),
+        );
+    }
+
+    #[test]
+    fn returns_none_without_a_next_rel() {
+        let header = r#"<https://api.github.com/orgs/foo/repos?page=1>; rel="first", <https://api.github.com/orgs/foo/repos?page=5>; rel="last""#;
+
+        assert_eq!(parse_next_link(header), None);
+    }
+
+    #[test]
+    fn finds_next_as_the_only_link() {
+        let header = r#"<https://api.github.com/orgs/foo/repos?page=2>; rel="next""#;
+
+        assert_eq!(
+            parse_next_link(header),
+            Some("https://api.github.com/orgs/foo/repos?page=2".to_string()),
+        );
+    }
+}
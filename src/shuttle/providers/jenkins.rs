@@ -3,22 +3,30 @@ use std::error::Error;
 
 use serde::Deserialize;
 
-use crate::shuttle::{Item, Provider};
+use crate::shuttle::{Action, Item, Provider};
+use crate::shuttle::providers::{Auth, get_with_auth};
 
 pub struct Jenkins {
     endpoint: String,
+    auth: Auth,
 }
 
 impl Jenkins {
     pub fn new(endpoint: impl Into<String>) -> Self {
-        Self { endpoint: endpoint.into() }
+        Self { endpoint: endpoint.into(), auth: Auth::None }
+    }
+
+    /// Authenticate requests to this provider, e.g. with an API token.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
     }
 }
 
 impl Provider for Jenkins {
     fn load(&self) -> Result<Vec<Item>, Box<dyn Error + Send + Sync>> {
         let url = format!("{}/api/json", self.endpoint);
-        let response: Response = ureq::get(&url).call()?.into_json()?;
+        let response: Response = get_with_auth(&url, &self.auth)?.into_json()?;
         Ok(response.jobs.into_iter().map(Into::into).collect())
     }
 }
@@ -41,9 +49,11 @@ impl From<Job> for Item {
         let haystack = job.name.to_lowercase();
 
         Item {
-            value: job.url,
+            value: job.url.clone(),
             label: job.name,
             haystack,
+            action: Action::Open(job.url),
+            alt_action: None,
         }
     }
 }
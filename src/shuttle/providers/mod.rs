@@ -1,12 +1,19 @@
 use std::error::Error;
+use std::fmt;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+pub use docker::DockerRegistry;
 pub use github::Github;
 pub use jenkins::Jenkins;
+pub use rest::RestProvider;
 
 use crate::Item;
 
+mod docker;
 mod github;
 mod jenkins;
+mod rest;
 
 pub trait Provider: Send + Sync {
     fn title(&self) -> String {
@@ -16,3 +23,161 @@ pub trait Provider: Send + Sync {
     /// Loads all items that this provider can provide.
     fn load(&self) -> Result<Vec<Item>, Box<dyn Error + Send + Sync>>;
 }
+
+/// Credentials to send along with a provider's outgoing requests.
+pub enum Auth {
+    None,
+    Bearer(String),
+    Basic { username: String, password: String },
+}
+
+impl Auth {
+    fn authorization_header(&self) -> Option<String> {
+        match self {
+            Auth::None => None,
+            Auth::Bearer(token) => Some(format!("Bearer {}", token)),
+            Auth::Basic { username, password } => {
+                let credentials = base64_encode(format!("{}:{}", username, password).as_bytes());
+                Some(format!("Basic {}", credentials))
+            }
+        }
+    }
+}
+
+/// The request was rejected because of rate limiting, and may succeed again after
+/// `retry_after` has elapsed.
+#[derive(Debug)]
+pub struct RateLimitError {
+    pub retry_after: Duration,
+}
+
+impl fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rate limited, retry after {:?}", self.retry_after)
+    }
+}
+
+impl Error for RateLimitError {}
+
+const MAX_RETRIES: u32 = 3;
+
+/// Upper bound on how long a single retry will sleep for. `load_items_from_providers`
+/// runs providers on the shared rayon pool, so an unbounded sleep (GitHub's
+/// `X-RateLimit-Reset` can be up to an hour out) would tie up a worker and stall
+/// unrelated providers; we'd rather give up and surface a [`RateLimitError`] sooner.
+const MAX_RETRY_SLEEP: Duration = Duration::from_secs(30);
+
+/// Performs a `GET` request with the given auth applied, transparently retrying a
+/// handful of times with backoff when the response indicates we're actually being
+/// rate limited (`429`, or `403` with rate-limit headers present). A plain `403`
+/// without those headers is a permission/auth failure, not rate limiting, and is
+/// returned immediately instead of being retried. If the server is still
+/// rate-limiting us after the retries are exhausted, a [`RateLimitError`] is returned
+/// instead of the raw HTTP error.
+fn get_with_auth(url: &str, auth: &Auth) -> Result<ureq::Response, Box<dyn Error + Send + Sync>> {
+    for attempt in 0..=MAX_RETRIES {
+        let mut request = ureq::get(url);
+
+        if let Some(header) = auth.authorization_header() {
+            request = request.set("Authorization", &header);
+        }
+
+        match request.call() {
+            Ok(response) => return Ok(response),
+
+            Err(ureq::Error::Status(status, response)) if status == 429 || (status == 403 && is_rate_limited_403(&response)) => {
+                let retry_after = rate_limit_retry_after(&response);
+
+                if attempt == MAX_RETRIES {
+                    return Err(Box::new(RateLimitError { retry_after }));
+                }
+
+                thread::sleep(retry_after);
+            }
+
+            Err(err) => return Err(Box::new(err)),
+        }
+    }
+
+    unreachable!("loop always returns before exhausting its range")
+}
+
+/// Whether a `403` response is GitHub's rate limiting (primary: `X-RateLimit-Remaining:
+/// 0`, or secondary/abuse detection: a `Retry-After` header) rather than a plain
+/// permission/auth failure, which also comes back as `403` but should not be retried.
+fn is_rate_limited_403(response: &ureq::Response) -> bool {
+    response.header("Retry-After").is_some()
+        || response.header("X-RateLimit-Remaining") == Some("0")
+}
+
+/// Reads `Retry-After` (delta-seconds) or, failing that, GitHub's `X-RateLimit-Reset`
+/// (a Unix timestamp) from a rate-limited response, capped at [`MAX_RETRY_SLEEP`].
+fn rate_limit_retry_after(response: &ureq::Response) -> Duration {
+    let retry_after = if let Some(seconds) = response.header("Retry-After").and_then(|v| v.parse().ok()) {
+        Duration::from_secs(seconds)
+    } else if let Some(reset_at) = response.header("X-RateLimit-Reset").and_then(|v| v.parse::<u64>().ok()) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        Duration::from_secs(reset_at.saturating_sub(now))
+    } else {
+        Duration::from_secs(5)
+    };
+
+    retry_after.min(MAX_RETRY_SLEEP)
+}
+
+/// Minimal RFC 4648 base64 encoder, just enough to build a basic-auth header without
+/// pulling in a dependency for it.
+fn base64_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity((input.len() + 2) / 3 * 4);
+
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char);
+
+        out.push(match b1 {
+            Some(b1) => ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char,
+            None => '=',
+        });
+
+        out.push(match b2 {
+            Some(b2) => ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base64_encode;
+
+    #[test]
+    fn encodes_input_with_no_padding() {
+        // 6 bytes -> a multiple of 3, so no `=` padding is needed
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn encodes_input_needing_two_padding_bytes() {
+        // 4 bytes leaves a 1-byte remainder in the final chunk -> `==`
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+    }
+
+    #[test]
+    fn encodes_input_needing_one_padding_byte() {
+        // 5 bytes leaves a 2-byte remainder in the final chunk -> one `=`
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+    }
+
+    #[test]
+    fn encodes_empty_input() {
+        assert_eq!(base64_encode(b""), "");
+    }
+}
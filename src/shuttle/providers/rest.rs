@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::shuttle::{Action, Item, Provider};
+use crate::shuttle::providers::{Auth, get_with_auth};
+
+/// A provider for arbitrary JSON/REST APIs: point it at a URL and tell it, via
+/// dotted JSONPath-like selectors, where to find the array of entries and the
+/// `label`/`value`/`haystack` fields within each entry.
+pub struct RestProvider {
+    url: String,
+    auth: Auth,
+    items_path: Option<String>,
+    label_path: String,
+    value_path: String,
+    haystack_path: Option<String>,
+}
+
+impl RestProvider {
+    /// `label_path` and `value_path` are dotted field paths (e.g. `"name"` or
+    /// `"repo.full_name"`) resolved against each entry of the response. Entries are
+    /// opened via `Action::Open`, so `value_path` should resolve to a URL.
+    pub fn new(url: impl Into<String>, label_path: impl Into<String>, value_path: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            auth: Auth::None,
+            items_path: None,
+            label_path: label_path.into(),
+            value_path: value_path.into(),
+            haystack_path: None,
+        }
+    }
+
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// The dotted path to the array of entries within the response. Leave unset if
+    /// the response body itself is that array.
+    pub fn with_items_path(mut self, items_path: impl Into<String>) -> Self {
+        self.items_path = Some(items_path.into());
+        self
+    }
+
+    /// The dotted path to an entry's haystack field. Defaults to the label.
+    pub fn with_haystack_path(mut self, haystack_path: impl Into<String>) -> Self {
+        self.haystack_path = Some(haystack_path.into());
+        self
+    }
+}
+
+impl Provider for RestProvider {
+    fn load(&self) -> Result<Vec<Item>, Box<dyn Error + Send + Sync>> {
+        let body: Value = get_with_auth(&self.url, &self.auth)?.into_json()?;
+
+        let entries = match &self.items_path {
+            Some(path) => select(&body, path).ok_or_else(|| format!("no field at `{}`", path))?,
+            None => &body,
+        };
+
+        let entries = entries.as_array().ok_or("expected a JSON array of entries")?;
+
+        let items = entries.iter()
+            .filter_map(|entry| {
+                let label = select_string(entry, &self.label_path)?;
+                let value = select_string(entry, &self.value_path)?;
+
+                let haystack = self.haystack_path
+                    .as_deref()
+                    .and_then(|path| select_string(entry, path))
+                    .unwrap_or_else(|| label.clone());
+
+                Some(Item {
+                    label,
+                    haystack,
+                    action: Action::Open(value.clone()),
+                    alt_action: None,
+                    value,
+                })
+            })
+            .collect();
+
+        Ok(items)
+    }
+}
+
+/// Resolves a dotted path like `"repo.full_name"` against a JSON value.
+fn select<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| value.get(segment))
+}
+
+fn select_string(value: &Value, path: &str) -> Option<String> {
+    match select(value, path)? {
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}